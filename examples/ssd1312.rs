@@ -15,7 +15,7 @@ use panic_probe as _;
 use defmt_rtt as _;
 
 // use ssd1312::Ssd1312;
-use ssd1312::{Ssd1312, TextStyles};
+use ssd1312::{I2cInterface, Ssd1312, TextStyles};
     bind_interrupts!(struct Irqs {
     I2C1_EV => i2c::EventInterruptHandler<peripherals::I2C1>;
     I2C1_ER => i2c::ErrorInterruptHandler<peripherals::I2C1>;
@@ -68,7 +68,7 @@ async fn main(_spawner: Spawner) -> ! {
         Hertz(400_000),
         Default::default(),
     );
-    let mut oled = Ssd1312::new(i2c);
+    let mut oled: Ssd1312<_> = Ssd1312::new(I2cInterface::new(i2c));
     let mut delay = Delay;
 
     match oled.init(&mut delay) {
@@ -0,0 +1,9 @@
+/// 位图数据里像素的字节排列方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BitmapLayout {
+    /// 逐行、每字节8个像素、MSB在前（XBM/embedded-graphics `ImageRaw`的约定）
+    HorizontalMsb,
+    /// 按页竖排：每字节是同一列内纵向8个像素（LSB在上），常见于C项目里
+    /// `oledfont.h`/`bmp.h`一类取模工具导出的字库/图片数组
+    VerticalPacked,
+}
@@ -0,0 +1,135 @@
+use embedded_hal::delay::DelayNs;
+use embedded_hal::digital::OutputPin;
+use embedded_hal::i2c::I2c as I2cTrait;
+use embedded_hal::spi::SpiDevice;
+
+/// SSD1312 I2C从地址（SA0=0时，对应D/C#接VSS）
+pub(crate) const SSD1312_I2C_ADDR: u8 = 0x3C;
+
+/// 显示屏传输层抽象
+///
+/// `Ssd1312`本身只负责生成命令/数据字节流，具体怎么把这些字节发到面板
+/// 由实现了这个trait的类型决定，这样同一套绘图代码既能跑在I2C上，也能
+/// 跑在更快的SPI上，不再绑死某一款MCU的HAL。
+pub trait DisplayInterface {
+    /// 总线错误类型
+    type Error;
+
+    /// 发送一串命令字节
+    fn send_commands(&mut self, cmds: &[u8]) -> Result<(), Self::Error>;
+
+    /// 发送显存数据
+    fn send_data(&mut self, data: &[u8]) -> Result<(), Self::Error>;
+}
+
+/// I2C接口（手册6.1.5节 I2C写入模式），沿用控制字节成帧方式
+pub struct I2cInterface<I2C> {
+    i2c: I2C,
+    addr: u8,
+}
+
+impl<I2C: I2cTrait> I2cInterface<I2C> {
+    /// 使用默认从地址（0x3C，SA0接VSS）创建I2C接口
+    pub fn new(i2c: I2C) -> Self {
+        Self::with_addr(i2c, SSD1312_I2C_ADDR)
+    }
+
+    /// 使用指定从地址创建I2C接口（例如SA0接VDD时为0x3D）
+    pub fn with_addr(i2c: I2C, addr: u8) -> Self {
+        Self { i2c, addr }
+    }
+}
+
+impl<I2C: I2cTrait> DisplayInterface for I2cInterface<I2C> {
+    type Error = I2C::Error;
+
+    fn send_commands(&mut self, cmds: &[u8]) -> Result<(), Self::Error> {
+        for &cmd in cmds {
+            let data = [0x00, cmd]; // 控制字节(Co=0, D/C#=0) + 命令
+            self.i2c.write(self.addr, &data)?;
+        }
+        Ok(())
+    }
+
+    fn send_data(&mut self, data: &[u8]) -> Result<(), Self::Error> {
+        if data.is_empty() {
+            return Ok(());
+        }
+
+        let mut buf = [0u8; 129]; // 1字节控制+128字节数据
+        buf[0] = 0x40; // 控制字节(Co=0, D/C#=1)
+        let len = data.len().min(128);
+        buf[1..1 + len].copy_from_slice(&data[..len]);
+        self.i2c.write(self.addr, &buf[..1 + len])
+    }
+}
+
+/// 4线SPI接口的错误类型：区分总线错误和D/C、RES引脚错误
+#[derive(Debug)]
+pub enum SpiInterfaceError<SPIE, PINE> {
+    /// SPI总线错误
+    Spi(SPIE),
+    /// D/C或RES引脚操作错误
+    Pin(PINE),
+}
+
+/// 4线SPI接口：用D/C脚区分命令/数据（低=命令，高=数据），可选RES复位脚。
+/// 片选由`SPI`自身（`embedded-hal` 1.0的`SpiDevice`）管理。
+pub struct SpiInterface<SPI, DC, RES = DC> {
+    spi: SPI,
+    dc: DC,
+    res: Option<RES>,
+}
+
+impl<SPI, DC, SPIE, PINE> SpiInterface<SPI, DC, DC>
+where
+    SPI: SpiDevice<Error = SPIE>,
+    DC: OutputPin<Error = PINE>,
+{
+    /// 创建不带RES复位脚的SPI接口（复位需由调用方在此之外处理）
+    pub fn new(spi: SPI, dc: DC) -> Self {
+        Self { spi, dc, res: None }
+    }
+}
+
+impl<SPI, DC, RES, SPIE, PINE> SpiInterface<SPI, DC, RES>
+where
+    SPI: SpiDevice<Error = SPIE>,
+    DC: OutputPin<Error = PINE>,
+    RES: OutputPin<Error = PINE>,
+{
+    /// 创建带RES复位脚的SPI接口
+    pub fn with_reset(spi: SPI, dc: DC, res: RES) -> Self {
+        Self { spi, dc, res: Some(res) }
+    }
+
+    /// 执行硬件复位时序：拉低RES至少10us后拉高，再等待面板稳定下来
+    pub fn reset(&mut self, delay: &mut impl DelayNs) -> Result<(), SpiInterfaceError<SPIE, PINE>> {
+        if let Some(res) = self.res.as_mut() {
+            res.set_low().map_err(SpiInterfaceError::Pin)?;
+            delay.delay_us(10u32);
+            res.set_high().map_err(SpiInterfaceError::Pin)?;
+            delay.delay_ms(10u32);
+        }
+        Ok(())
+    }
+}
+
+impl<SPI, DC, RES, SPIE, PINE> DisplayInterface for SpiInterface<SPI, DC, RES>
+where
+    SPI: SpiDevice<Error = SPIE>,
+    DC: OutputPin<Error = PINE>,
+    RES: OutputPin<Error = PINE>,
+{
+    type Error = SpiInterfaceError<SPIE, PINE>;
+
+    fn send_commands(&mut self, cmds: &[u8]) -> Result<(), Self::Error> {
+        self.dc.set_low().map_err(SpiInterfaceError::Pin)?;
+        self.spi.write(cmds).map_err(SpiInterfaceError::Spi)
+    }
+
+    fn send_data(&mut self, data: &[u8]) -> Result<(), Self::Error> {
+        self.dc.set_high().map_err(SpiInterfaceError::Pin)?;
+        self.spi.write(data).map_err(SpiInterfaceError::Spi)
+    }
+}
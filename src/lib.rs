@@ -1,7 +1,4 @@
 #![no_std]
-use embassy_stm32::i2c::Error;
-use embassy_time::Delay;
-use embedded_hal::i2c::I2c as I2cTrait;
 use embedded_hal::delay::DelayNs;
 
 // embedded-graphics相关导入
@@ -9,99 +6,152 @@ use embedded_graphics::{
     draw_target::DrawTarget, geometry::{Dimensions, OriginDimensions, Size}, mono_font::{ascii::{FONT_6X10, FONT_8X13}, MonoTextStyle}, pixelcolor::BinaryColor, prelude::*, primitives::{Line, PrimitiveStyle, Rectangle, StyledDrawable}, text::{Baseline, Text, TextStyle}
 };
 
-/// SSD1312 I2C从地址（SA0=0时，对应D/C#接VSS）
-const SSD1312_I2C_ADDR: u8 = 0x3C;
-/// SSD1312显示屏尺寸
-const SCREEN_WIDTH: u8 = 128;
-const SCREEN_HEIGHT: u8 = 64;
-const PAGE_COUNT: u8 = 8;
+mod interface;
+pub use interface::{DisplayInterface, I2cInterface, SpiInterface, SpiInterfaceError};
+
+mod scroll;
+pub use scroll::{ScrollDirection, ScrollInterval};
+
+mod rotation;
+pub use rotation::Rotation;
+
+mod bitmap;
+pub use bitmap::BitmapLayout;
+
+/// 所有受支持分辨率里显存占用最大的一款（128x64/8），缓冲区按这个尺寸分配，
+/// 小尺寸面板只使用其中的前`W*H/8`字节。这样`W`/`H`可以是const泛型参数，而
+/// 不用依赖还没稳定的“数组长度里做泛型常量运算”特性。
+const MAX_BUFFER_SIZE: usize = 128 * 64 / 8;
 
 /// SSD1312驱动结构体
-pub struct Ssd1312<I2C> {
-    i2c: I2C,
-    buffer: [u8; 1024], // 128x64/8 = 1024字节显存缓冲区
+///
+/// `W`/`H`是面板的可见分辨率（像素），默认128x64。`COL_OFFSET`是面板RAM列地址
+/// 相对可见区域的偏移，SH1106一类控制器有132根SEG输出但只有128列可见，
+/// 贴片决定了可见区域从第几列开始（通常是2）。
+pub struct Ssd1312<DI, const W: usize = 128, const H: usize = 64, const COL_OFFSET: u8 = 0> {
+    interface: DI,
+    buffer: [u8; MAX_BUFFER_SIZE],
+    scroll_active: bool,
+    // 脏矩形：记录自上次display()以来哪些列/页被改动过，display()只刷这块区域
+    dirty: bool,
+    dirty_x_min: u8,
+    dirty_x_max: u8,
+    dirty_page_min: u8,
+    dirty_page_max: u8,
 }
 
-impl<I2C: I2cTrait<Error = Error>> Ssd1312<I2C> {
+/// 128x64，默认分辨率（现有调用方不用改代码）
+pub type Ssd1312_128x64<DI> = Ssd1312<DI, 128, 64, 0>;
+/// 128x32
+pub type Ssd1312_128x32<DI> = Ssd1312<DI, 128, 32, 0>;
+/// 64x48：玻璃只占控制器128根SEG里的一段。假设是大多数64x48 SSD130x系列模组
+/// 常见的居中走线，即列地址偏移32（(128-64)/2）——这是个假设，没有拿实际
+/// 面板/手册核实过，手头有具体模组的话请对照验证并按需调整
+pub type Ssd1312_64x48<DI> = Ssd1312<DI, 64, 48, 32>;
+/// SH1106风格面板：132根SEG输出，128列可见，列地址偏移2
+pub type Ssd1312Sh1106_132x64<DI> = Ssd1312<DI, 128, 64, 2>;
+
+impl<DI: DisplayInterface, const W: usize, const H: usize, const COL_OFFSET: u8> Ssd1312<DI, W, H, COL_OFFSET> {
+    /// 每页的行数固定为8（SSD131x系列都是按页组织显存的）
+    const PAGE_COUNT: usize = H / 8;
+    /// 实际用到的显存字节数
+    const BUFFER_LEN: usize = W * H / 8;
+
+    // 缓冲区是按`MAX_BUFFER_SIZE`固定分配的，`W*H/8`超过这个大小会越界panic；
+    // `display_keep_scroll`里拷贝脏矩形用的暂存数组也固定是128字节宽，所以
+    // `W`不能超过128。这里把越界的几何参数变成编译错误，而不是等运行时在
+    // 某次`set_pixel`/`display`上panic。
+    const GEOMETRY_FITS_BUFFER: () =
+        assert!(W * H / 8 <= MAX_BUFFER_SIZE && W <= 128, "W*H/8 must fit in MAX_BUFFER_SIZE and W must be <= 128");
+
     /// 创建新的SSD1312驱动实例
-    pub fn new(i2c: I2C) -> Self {
-        Self { 
-            i2c,
-            buffer: [0u8; 1024],
-        }
+    pub fn new(interface: DI) -> Self {
+        let () = Self::GEOMETRY_FITS_BUFFER;
+        let mut driver = Self {
+            interface,
+            buffer: [0u8; MAX_BUFFER_SIZE],
+            scroll_active: false,
+            dirty: false,
+            dirty_x_min: 0,
+            dirty_x_max: 0,
+            dirty_page_min: 0,
+            dirty_page_max: 0,
+        };
+        driver.force_full_flush(); // 第一帧总是全量刷新
+        driver
     }
 
     /// 发送命令到SSD1312（手册6.1.5节 I2C写入模式）
-    fn send_command(&mut self, cmd: u8) -> Result<(), Error> {
-        let data = [0x00, cmd]; // 控制字节(Co=0, D/C#=0) + 命令
-        self.i2c.write(SSD1312_I2C_ADDR, &data)
+    fn send_command(&mut self, cmd: u8) -> Result<(), DI::Error> {
+        self.interface.send_commands(&[cmd])
     }
 
     /// 发送多个命令
-    fn send_commands(&mut self, cmds: &[u8]) -> Result<(), Error> {
-        for &cmd in cmds {
-            self.send_command(cmd)?;
-        }
-        Ok(())
+    fn send_commands(&mut self, cmds: &[u8]) -> Result<(), DI::Error> {
+        self.interface.send_commands(cmds)
     }
 
     /// 发送数据到SSD1312（手册6.1.5节）
-    fn send_data(&mut self, data: &[u8]) -> Result<(), Error> {
-        if data.is_empty() {
-            return Ok(());
-        }
-        
-        let mut buf = [0u8; 129]; // 1字节控制+128字节数据
-        buf[0] = 0x40; // 控制字节(Co=0, D/C#=1)
-        let len = data.len().min(128);
-        buf[1..1+len].copy_from_slice(&data[..len]);
-        self.i2c.write(SSD1312_I2C_ADDR, &buf[..1+len])
+    fn send_data(&mut self, data: &[u8]) -> Result<(), DI::Error> {
+        self.interface.send_data(data)
     }
 
     /// 设置页地址（手册2.1.14节）
-    fn set_page(&mut self, page: u8) -> Result<(), Error> {
-        if page < 8 {
+    fn set_page(&mut self, page: u8) -> Result<(), DI::Error> {
+        if (page as usize) < Self::PAGE_COUNT {
             self.send_command(0xB0 | page)
         } else {
             Ok(())
         }
     }
 
-    /// 设置列地址（手册2.1.1和2.1.2节）
-    fn set_column(&mut self, col: u8) -> Result<(), Error> {
-        if col < 128 {
-            self.send_command(col & 0x0F)?;        // 低4位
-            self.send_command(0x10 | (col >> 4))   // 高4位
+    /// 设置列地址（手册2.1.1和2.1.2节），加上面板的RAM列偏移（SH1106等）
+    fn set_column(&mut self, col: u8) -> Result<(), DI::Error> {
+        if (col as usize) < W {
+            let addr = col + COL_OFFSET;
+            self.send_command(addr & 0x0F)?;        // 低4位
+            self.send_command(0x10 | (addr >> 4))   // 高4位
         } else {
             Ok(())
         }
     }
 
+    /// 面板的SEG引脚硬件配置（手册2.1.19节，命令0xDA）
+    ///
+    /// 16/32行高的面板用顺序COM配置（0x02），其余（64行等）用交替配置（0x12），
+    /// 这和SSD130x系列参考设计的COM布线习惯一致。
+    const fn com_pin_config() -> u8 {
+        match H {
+            16 | 32 => 0x02,
+            _ => 0x12,
+        }
+    }
+
     /// 初始化SSD1312（手册6.9节和Table 1-1）
-    pub fn init(&mut self, delay: &mut Delay) -> Result<(), Error> {
+    pub fn init(&mut self, delay: &mut impl DelayNs) -> Result<(), DI::Error> {
         // 按照手册6.9.2节的电荷泵上电序列
-        
+
         // 1. 等待VDD稳定（至少20ms）
         delay.delay_ms(20u32);
-        
+
         // 2. 显示关闭
         self.send_command(0xAE)?;
-        
+
         // 3. 设置显示时钟分频比/振荡器频率（手册2.1.17节）
         self.send_commands(&[0xD5, 0x80])?; // 分频比=1，默认频率
-        
+
         // 4. 设置复用比（手册2.1.11节）
-        self.send_commands(&[0xA8, 0x3F])?; // 64MUX (63+1)
-        
+        self.send_commands(&[0xA8, (H - 1) as u8])?; // 复用比 = H-1
+
         // 5. 设置显示偏移（手册2.1.16节）
         self.send_commands(&[0xD3, 0x00])?; // 无偏移
-        
+
         // 6. 设置显示起始行（手册2.1.6节）
         self.send_command(0x40)?; // 起始行=0
-        
+
         // 7. 启用内部电荷泵（手册2.1.22节）
         self.send_commands(&[0x8D, 0x12])?; // 启用电荷泵，7.5V模式
-        
+
         // 20 02 A0 C8
         // 20 02 A1 C0
         // 20 09 A1 C8
@@ -109,100 +159,158 @@ impl<I2C: I2cTrait<Error = Error>> Ssd1312<I2C> {
 
         // 8. 内存寻址模式（手册2.1.3节）
         self.send_commands(&[0x20, 0x02])?; // 页寻址模式
-        
+
         // 9. 段重映射（手册2.1.8节）
         self.send_command(0xA0)?; // 列地址0映射到SEG0
-        
+
         // 10. COM输出扫描方向（手册2.1.15节）
         self.send_command(0xC8)?; // 垂直翻转
-        
+
         // 11. SEG引脚硬件配置（手册2.1.19节）
-        self.send_commands(&[0xDA, 0x12])?; // 交替SEG引脚配置
-        
+        self.send_commands(&[0xDA, Self::com_pin_config()])?;
+
         // 12. 设置对比度（手册2.1.7节）
         self.send_commands(&[0x81, 0x7F])?; // 默认对比度
-        
+
         // 13. 设置预充电周期（手册2.1.18节）
         self.send_commands(&[0xD9, 0x22])?; // Phase1=2, Phase2=2
-        
+
         // 14. 设置VCOMH电压（手册2.1.20节）
         self.send_commands(&[0xDB, 0x20])?; // ~0.77 x VCC
-        
+
         // 15. 恢复RAM内容显示（手册2.1.9节）
         self.send_command(0xA4)?;
-        
+
         // 16. 正常显示模式（手册2.1.10节）
         self.send_command(0xA6)?;
-        
+
         // 17. 开启显示（手册2.1.13节）
         self.send_command(0xAF)?;
-        
+
         // 18. 等待显示稳定（手册6.9.2节，至少100ms）
         delay.delay_ms(100u32);
-        
+
         Ok(())
     }
 
     /// 清除屏幕缓冲区
+    ///
+    /// 这是个整块内存的fill，不经过[`Ssd1312::set_pixel`]，所以这里强制
+    /// 做一次全量刷新标记，否则脏矩形可能覆盖不到被清掉的旧内容。
     pub fn clear_buffer(&mut self) {
         self.buffer.fill(0);
+        self.force_full_flush();
     }
 
     /// 在缓冲区中设置像素
     pub fn set_pixel(&mut self, x: u8, y: u8, on: bool) {
-        if x >= SCREEN_WIDTH || y >= SCREEN_HEIGHT {
+        if x as usize >= W || y as usize >= H {
             return;
         }
-        
+
         let page = (y / 8) as usize;
         let bit_pos = y % 8;
-        let index = page * SCREEN_WIDTH as usize + x as usize;
-        
-        if index < self.buffer.len() {
+        let index = page * W + x as usize;
+
+        if index < Self::BUFFER_LEN {
             if on {
                 self.buffer[index] |= 1 << bit_pos;
             } else {
                 self.buffer[index] &= !(1 << bit_pos);
             }
         }
+
+        self.mark_dirty(x, page as u8);
+    }
+
+    /// 把一个(列, 页)坐标并入脏矩形
+    fn mark_dirty(&mut self, x: u8, page: u8) {
+        if self.dirty {
+            self.dirty_x_min = self.dirty_x_min.min(x);
+            self.dirty_x_max = self.dirty_x_max.max(x);
+            self.dirty_page_min = self.dirty_page_min.min(page);
+            self.dirty_page_max = self.dirty_page_max.max(page);
+        } else {
+            self.dirty = true;
+            self.dirty_x_min = x;
+            self.dirty_x_max = x;
+            self.dirty_page_min = page;
+            self.dirty_page_max = page;
+        }
+    }
+
+    /// 强制下一次[`Ssd1312::display`]全量刷新整个屏幕
+    ///
+    /// 用于第一帧，或者[`Ssd1312::set_invert`]、旋转这类不经过`set_pixel`
+    /// 但会改变画面呈现方式的操作之后。
+    pub fn force_full_flush(&mut self) {
+        self.dirty = true;
+        self.dirty_x_min = 0;
+        self.dirty_x_max = (W - 1) as u8;
+        self.dirty_page_min = 0;
+        self.dirty_page_max = (Self::PAGE_COUNT - 1) as u8;
     }
 
     /// 将缓冲区内容写入显示屏
-    pub fn display(&mut self) -> Result<(), Error> {
-        for page in 0..PAGE_COUNT {
+    ///
+    /// 如果硬件滚动正在运行，会先发送`0x2E`关闭它——滚动开启时写RAM会导致
+    /// 画面错乱（见手册滚动命令说明），这是个必须遵守的前置条件。如果确实
+    /// 需要在滚动运行时刷新（比如只是重新设置了滚动参数），用
+    /// [`Ssd1312::display_keep_scroll`]。
+    pub fn display(&mut self) -> Result<(), DI::Error> {
+        if self.scroll_active {
+            self.send_command(0x2E)?; // 关闭滚动
+            self.scroll_active = false;
+        }
+        self.display_keep_scroll()
+    }
+
+    /// 将缓冲区内容写入显示屏，但不自动关闭正在运行的硬件滚动
+    ///
+    /// 只刷脏矩形覆盖的页/列范围（见[`Ssd1312::set_pixel`]），不是每次都
+    /// 重发全部页——增量更新的UI（计数器、进度条之类）可以少发很多字节。
+    /// 调用方需要自己保证跳过滚动停止这么做是安全的；绝大多数场景应该用
+    /// [`Ssd1312::display`]。
+    pub fn display_keep_scroll(&mut self) -> Result<(), DI::Error> {
+        if !self.dirty {
+            return Ok(());
+        }
+
+        let x_min = self.dirty_x_min;
+        let width = (self.dirty_x_max - x_min + 1) as usize;
+
+        for page in self.dirty_page_min..=self.dirty_page_max {
             self.set_page(page)?;
-            self.set_column(0)?;
-            
-            let start_idx = page as usize * SCREEN_WIDTH as usize;
-            let end_idx = start_idx + SCREEN_WIDTH as usize;
-            
-            if end_idx <= self.buffer.len() {
-                let mut page_data = [0u8; 128];
-                page_data.copy_from_slice(&self.buffer[start_idx..end_idx]);
-                self.send_data(&page_data)?;
-            }
+            self.set_column(x_min)?;
+
+            let start_idx = page as usize * W + x_min as usize;
+            let mut page_data = [0u8; 128];
+            page_data[..width].copy_from_slice(&self.buffer[start_idx..start_idx + width]);
+            self.send_data(&page_data[..width])?;
         }
+
+        self.dirty = false;
         Ok(())
     }
 
     /// 清除整个显示屏（直接写入硬件）
-    pub fn clear(&mut self) -> Result<(), Error> {
+    pub fn clear(&mut self) -> Result<(), DI::Error> {
         self.clear_buffer();
         self.display()
     }
 
     /// 绘制单个像素（立即显示）
-    pub fn draw_pixel(&mut self, x: u8, y: u8) -> Result<(), Error> {
+    pub fn draw_pixel(&mut self, x: u8, y: u8) -> Result<(), DI::Error> {
         self.set_pixel(x, y, true);
-        
+
         // 只更新对应的页
-        if x < SCREEN_WIDTH && y < SCREEN_HEIGHT {
+        if (x as usize) < W && (y as usize) < H {
             let page = y / 8;
             self.set_page(page)?;
             self.set_column(x)?;
-            
-            let index = page as usize * SCREEN_WIDTH as usize + x as usize;
-            if index < self.buffer.len() {
+
+            let index = page as usize * W + x as usize;
+            if index < Self::BUFFER_LEN {
                 let pixel_data = self.buffer[index];
                 self.send_data(&[pixel_data])?;
             }
@@ -211,9 +319,9 @@ impl<I2C: I2cTrait<Error = Error>> Ssd1312<I2C> {
     }
 
     /// 绘制水平线
-    pub fn draw_horizontal_line(&mut self, x: u8, y: u8, width: u8) -> Result<(), Error> {
+    pub fn draw_horizontal_line(&mut self, x: u8, y: u8, width: u8) -> Result<(), DI::Error> {
         for i in 0..width {
-            if x + i < SCREEN_WIDTH {
+            if ((x + i) as usize) < W {
                 self.set_pixel(x + i, y, true);
             }
         }
@@ -221,9 +329,9 @@ impl<I2C: I2cTrait<Error = Error>> Ssd1312<I2C> {
     }
 
     /// 绘制垂直线
-    pub fn draw_vertical_line(&mut self, x: u8, y: u8, height: u8) -> Result<(), Error> {
+    pub fn draw_vertical_line(&mut self, x: u8, y: u8, height: u8) -> Result<(), DI::Error> {
         for i in 0..height {
-            if y + i < SCREEN_HEIGHT {
+            if ((y + i) as usize) < H {
                 self.set_pixel(x, y + i, true);
             }
         }
@@ -231,56 +339,57 @@ impl<I2C: I2cTrait<Error = Error>> Ssd1312<I2C> {
     }
 
     /// 绘制矩形边框
-    pub fn draw_rect(&mut self, x: u8, y: u8, width: u8, height: u8) -> Result<(), Error> {
-        // 使用embedded-graphics绘制
+    pub fn draw_rect(&mut self, x: u8, y: u8, width: u8, height: u8) -> Result<(), DI::Error> {
+        // 使用embedded-graphics绘制。draw_iter对Ssd1312是不可能失败的（Self::Error = ()），
+        // 所以这里直接忽略返回值即可。
         let rect = Rectangle::new(Point::new(x as i32, y as i32), Size::new(width as u32, height as u32));
         let style = PrimitiveStyle::with_stroke(BinaryColor::On, 1);
-        rect.draw_styled(&style, self).map_err(|_| Error::Overrun)?;
+        let _ = rect.draw_styled(&style, self);
         self.display()
     }
 
     /// 填充矩形
-    pub fn fill_rect(&mut self, x: u8, y: u8, width: u8, height: u8) -> Result<(), Error> {
+    pub fn fill_rect(&mut self, x: u8, y: u8, width: u8, height: u8) -> Result<(), DI::Error> {
         // 使用embedded-graphics绘制
         let rect = Rectangle::new(Point::new(x as i32, y as i32), Size::new(width as u32, height as u32));
         let style = PrimitiveStyle::with_fill(BinaryColor::On);
-        rect.draw_styled(&style, self).map_err(|_| Error::Overrun)?;
+        let _ = rect.draw_styled(&style, self);
         self.display()
     }
 
     /// 绘制文本 - 小字体（6x10）
-    pub fn draw_text_small(&mut self, text: &str, x: i32, y: i32) -> Result<(), Error> {
+    pub fn draw_text_small(&mut self, text: &str, x: i32, y: i32) -> Result<(), DI::Error> {
         let text_style = MonoTextStyle::new(&FONT_6X10, BinaryColor::On);
         let text_drawable = Text::with_baseline(text, Point::new(x, y), text_style, Baseline::Top);
-        text_drawable.draw(self).map_err(|_| Error::Overrun)?;
+        let _ = text_drawable.draw(self);
         self.display()
     }
 
     /// 绘制文本 - 中等字体（8x13）
-    pub fn draw_text_medium(&mut self, text: &str, x: i32, y: i32) -> Result<(), Error> {
+    pub fn draw_text_medium(&mut self, text: &str, x: i32, y: i32) -> Result<(), DI::Error> {
         let text_style = MonoTextStyle::new(&FONT_8X13, BinaryColor::On);
         let text_drawable = Text::with_baseline(text, Point::new(x, y), text_style, Baseline::Top);
-        text_drawable.draw(self).map_err(|_| Error::Overrun)?;
+        let _ = text_drawable.draw(self);
         self.display()
     }
 
     /// 绘制居中文本
-    pub fn draw_text_centered(&mut self, text: &str, y: i32, font_width: i32) -> Result<(), Error> {
+    pub fn draw_text_centered(&mut self, text: &str, y: i32, font_width: i32) -> Result<(), DI::Error> {
         let text_width = text.len() as i32 * font_width;
-        let x = (SCREEN_WIDTH as i32 - text_width) / 2;
+        let x = (W as i32 - text_width) / 2;
         self.draw_text_small(text, x, y)
     }
 
     /// 绘制线条
-    pub fn draw_line(&mut self, x0: i32, y0: i32, x1: i32, y1: i32) -> Result<(), Error> {
+    pub fn draw_line(&mut self, x0: i32, y0: i32, x1: i32, y1: i32) -> Result<(), DI::Error> {
         let line = Line::new(Point::new(x0, y0), Point::new(x1, y1));
         let style = PrimitiveStyle::with_stroke(BinaryColor::On, 1);
-        line.draw_styled(&style, self).map_err(|_| Error::Overrun)?;
+        let _ = line.draw_styled(&style, self);
         self.display()
     }
 
     /// 设置显示开关（手册2.1.13节）
-    pub fn set_display_on(&mut self, on: bool) -> Result<(), Error> {
+    pub fn set_display_on(&mut self, on: bool) -> Result<(), DI::Error> {
         if on {
             self.send_command(0xAF) // 显示开
         } else {
@@ -289,22 +398,199 @@ impl<I2C: I2cTrait<Error = Error>> Ssd1312<I2C> {
     }
 
     /// 设置对比度（手册2.1.7节）
-    pub fn set_contrast(&mut self, contrast: u8) -> Result<(), Error> {
+    pub fn set_contrast(&mut self, contrast: u8) -> Result<(), DI::Error> {
         self.send_commands(&[0x81, contrast])
     }
 
     /// 设置显示反色（手册2.1.10节）
-    pub fn set_invert(&mut self, invert: bool) -> Result<(), Error> {
+    pub fn set_invert(&mut self, invert: bool) -> Result<(), DI::Error> {
         if invert {
             self.send_command(0xA7) // 反色显示
         } else {
             self.send_command(0xA6) // 正常显示
         }
     }
+
+    /// 启动连续水平滚动（手册2.2.1/2.2.2节，命令0x26/0x27）
+    ///
+    /// `start_page`/`end_page`是页号，滚动覆盖这个页范围。
+    pub fn scroll_horizontal(
+        &mut self,
+        direction: ScrollDirection,
+        start_page: u8,
+        end_page: u8,
+        interval: ScrollInterval,
+    ) -> Result<(), DI::Error> {
+        let cmd = match direction {
+            ScrollDirection::Right => 0x26,
+            ScrollDirection::Left => 0x27,
+        };
+        self.send_commands(&[
+            cmd,
+            0x00,
+            start_page,
+            interval as u8,
+            end_page,
+            0x00,
+            0xFF,
+        ])?;
+        self.send_command(0x2F)?; // 激活滚动
+        self.scroll_active = true;
+        Ok(())
+    }
+
+    /// 启动连续对角线（垂直+水平）滚动（手册2.2.3/2.2.4节，命令0x29/0x2A）
+    ///
+    /// 需要先用[`Ssd1312::set_vertical_scroll_area`]配置垂直滚动区域，
+    /// `vertical_offset`是每次滚动的垂直偏移行数。
+    pub fn scroll_diagonal(
+        &mut self,
+        direction: ScrollDirection,
+        start_page: u8,
+        end_page: u8,
+        interval: ScrollInterval,
+        vertical_offset: u8,
+    ) -> Result<(), DI::Error> {
+        let cmd = match direction {
+            ScrollDirection::Right => 0x29,
+            ScrollDirection::Left => 0x2A,
+        };
+        self.send_commands(&[
+            cmd,
+            0x00,
+            start_page,
+            interval as u8,
+            end_page,
+            vertical_offset,
+        ])?;
+        self.send_command(0x2F)?; // 激活滚动
+        self.scroll_active = true;
+        Ok(())
+    }
+
+    /// 配置垂直滚动区域（手册2.1.21节，命令0xA3），对角线滚动前需要先调用
+    pub fn set_vertical_scroll_area(&mut self, fixed_rows: u8, scrolling_rows: u8) -> Result<(), DI::Error> {
+        self.send_commands(&[0xA3, fixed_rows, scrolling_rows])
+    }
+
+    /// 停止硬件滚动（手册2.2.5节，命令0x2E）
+    pub fn stop_scroll(&mut self) -> Result<(), DI::Error> {
+        self.send_command(0x2E)?;
+        self.scroll_active = false;
+        Ok(())
+    }
+
+    /// 设置段重映射（手册2.1.8节，命令0xA0/0xA1）
+    ///
+    /// `remapped = true`发0xA1（最后一列映射到SEG0），`false`发0xA0（列地址0映射到SEG0）。
+    pub fn set_segment_remap(&mut self, remapped: bool) -> Result<(), DI::Error> {
+        if remapped {
+            self.send_command(0xA1)
+        } else {
+            self.send_command(0xA0)
+        }
+    }
+
+    /// 设置COM输出扫描方向（手册2.1.15节，命令0xC0/0xC8）
+    ///
+    /// `reversed = true`发0xC8（COM[N-1]到COM0，垂直翻转），`false`发0xC0（COM0到COM[N-1]）。
+    pub fn set_com_scan_reverse(&mut self, reversed: bool) -> Result<(), DI::Error> {
+        if reversed {
+            self.send_command(0xC8)
+        } else {
+            self.send_command(0xC0)
+        }
+    }
+
+    /// 设置显示方向（组合发送对应的段重映射+COM扫描方向命令）
+    ///
+    /// GDDRAM内容不变，只是映射到玻璃面板的方式变了，所以之后会强制一次
+    /// 全量刷新（见[`Ssd1312::force_full_flush`]），确保画面跟新的方向一致。
+    pub fn set_rotation(&mut self, rotation: Rotation) -> Result<(), DI::Error> {
+        match rotation {
+            Rotation::Deg0 => {
+                self.set_segment_remap(false)?;
+                self.set_com_scan_reverse(true)?;
+            }
+            Rotation::Deg180 => {
+                self.set_segment_remap(true)?;
+                self.set_com_scan_reverse(false)?;
+            }
+        }
+        self.force_full_flush();
+        Ok(())
+    }
+
+    /// 绘制原始单色位图（手册之外：对应参考C驱动里的`OLED_ShowPicture`）
+    ///
+    /// `rect`的`top_left`是画在缓冲区里的位置，`size`是位图本身的宽高。
+    /// 同时支持`HorizontalMsb`（XBM/embedded-graphics的逐行MSB约定）和
+    /// `VerticalPacked`（常见C取模工具导出的按页竖排字节）两种数据排列，
+    /// 这样字体/取模工具生成的数组可以直接喂进来，不用先转换。超出屏幕
+    /// 范围的部分会被裁掉；`invert`为true时黑白互换。
+    pub fn draw_bitmap(
+        &mut self,
+        rect: Rectangle,
+        data: &[u8],
+        layout: BitmapLayout,
+        invert: bool,
+    ) -> Result<(), DI::Error> {
+        let x = rect.top_left.x;
+        let y = rect.top_left.y;
+        let width = rect.size.width as usize;
+        let height = rect.size.height as usize;
+
+        match layout {
+            BitmapLayout::HorizontalMsb => {
+                let stride = (width as usize).div_ceil(8); // 每行字节数
+                for row in 0..height as usize {
+                    for col in 0..width as usize {
+                        let byte_idx = row * stride + col / 8;
+                        let Some(&byte) = data.get(byte_idx) else {
+                            continue;
+                        };
+                        let on = (byte >> (7 - col % 8)) & 1 != 0;
+                        self.draw_bitmap_pixel(x, y, col, row, on ^ invert);
+                    }
+                }
+            }
+            BitmapLayout::VerticalPacked => {
+                let pages = (height as usize).div_ceil(8); // 每列的字节数
+                for col in 0..width as usize {
+                    for page in 0..pages {
+                        let byte_idx = page * width as usize + col;
+                        let Some(&byte) = data.get(byte_idx) else {
+                            continue;
+                        };
+                        for bit in 0..8usize {
+                            let row = page * 8 + bit;
+                            if row >= height as usize {
+                                break;
+                            }
+                            let on = (byte >> bit) & 1 != 0;
+                            self.draw_bitmap_pixel(x, y, col, row, on ^ invert);
+                        }
+                    }
+                }
+            }
+        }
+        self.display()
+    }
+
+    /// 把位图里(col, row)处的像素写进缓冲区，超出屏幕范围的直接丢弃
+    fn draw_bitmap_pixel(&mut self, x: i32, y: i32, col: usize, row: usize, on: bool) {
+        let px = x as i64 + col as i64;
+        let py = y as i64 + row as i64;
+        if px >= 0 && px < W as i64 && py >= 0 && py < H as i64 {
+            self.set_pixel(px as u8, py as u8, on);
+        }
+    }
 }
 
 // 实现embedded-graphics的DrawTarget trait
-impl<I2C: I2cTrait<Error = Error>> DrawTarget for Ssd1312<I2C> {
+impl<DI: DisplayInterface, const W: usize, const H: usize, const COL_OFFSET: u8> DrawTarget
+    for Ssd1312<DI, W, H, COL_OFFSET>
+{
     type Color = BinaryColor;
     type Error = ();
 
@@ -313,8 +599,8 @@ impl<I2C: I2cTrait<Error = Error>> DrawTarget for Ssd1312<I2C> {
         I: IntoIterator<Item = Pixel<Self::Color>>,
     {
         for Pixel(coord, color) in pixels.into_iter() {
-            if coord.x >= 0 && coord.x < SCREEN_WIDTH as i32 
-                && coord.y >= 0 && coord.y < SCREEN_HEIGHT as i32 {
+            if coord.x >= 0 && (coord.x as usize) < W
+                && coord.y >= 0 && (coord.y as usize) < H {
                 self.set_pixel(coord.x as u8, coord.y as u8, color.is_on());
             }
         }
@@ -323,9 +609,11 @@ impl<I2C: I2cTrait<Error = Error>> DrawTarget for Ssd1312<I2C> {
 }
 
 // 实现embedded-graphics的OriginDimensions trait
-impl<I2C: I2cTrait<Error = Error>> OriginDimensions for Ssd1312<I2C> {
+impl<DI: DisplayInterface, const W: usize, const H: usize, const COL_OFFSET: u8> OriginDimensions
+    for Ssd1312<DI, W, H, COL_OFFSET>
+{
     fn size(&self) -> Size {
-        Size::new(SCREEN_WIDTH as u32, SCREEN_HEIGHT as u32)
+        Size::new(W as u32, H as u32)
     }
 }
 
@@ -347,4 +635,4 @@ impl TextStyles {
     pub fn small_inverted() -> MonoTextStyle<'static, BinaryColor> {
         MonoTextStyle::new(&FONT_6X10, BinaryColor::Off)
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,11 @@
+/// 显示方向
+///
+/// 只影响GDDRAM内容怎么映射到玻璃面板（段重映射+COM扫描方向），不涉及
+/// 帧缓冲区本身，所以可以在运行时随时切换，不需要重新画一遍。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Rotation {
+    /// 正常方向（`init()`开机后的默认状态）：段重映射0xA0 + COM扫描反向0xC8
+    Deg0,
+    /// 旋转180°：段重映射0xA1 + COM扫描正向0xC0
+    Deg180,
+}
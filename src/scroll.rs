@@ -0,0 +1,29 @@
+/// 硬件滚动方向
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScrollDirection {
+    /// 向右滚动（命令0x26）
+    Right,
+    /// 向左滚动（命令0x27）
+    Left,
+}
+
+/// 滚动帧间隔（3 bit编码，对应命令`0x26`/`0x27`/`0x29`/`0x2A`之后的第3个参数字节）
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScrollInterval {
+    /// 每2帧滚动一次
+    Frames2 = 0x07,
+    /// 每3帧滚动一次
+    Frames3 = 0x04,
+    /// 每4帧滚动一次
+    Frames4 = 0x05,
+    /// 每5帧滚动一次
+    Frames5 = 0x00,
+    /// 每25帧滚动一次
+    Frames25 = 0x06,
+    /// 每64帧滚动一次
+    Frames64 = 0x01,
+    /// 每128帧滚动一次
+    Frames128 = 0x02,
+    /// 每256帧滚动一次
+    Frames256 = 0x03,
+}